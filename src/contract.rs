@@ -3,9 +3,9 @@
 mod state;
 
 use self::state::FungibleToken;
-use crate::state::InsufficientBalanceError;
+use crate::state::{InsufficientAllowanceError, InsufficientBalanceError};
 use async_trait::async_trait;
-use fungible::{Account, Message, Operation};
+use fungible::{Account, InitializationArgument, Message, Operation};
 use linera_sdk::base::{Amount, Owner};
 use linera_sdk::contract::system_api;
 use linera_sdk::{
@@ -15,6 +15,9 @@ use linera_sdk::{
 };
 use thiserror::Error;
 
+/// The maximum size, in bytes, of a transfer memo.
+const MAX_MEMO_LEN: usize = 256;
+
 linera_sdk::contract!(FungibleToken);
 
 impl WithContractAbi for FungibleToken {
@@ -29,10 +32,14 @@ impl Contract for FungibleToken {
     async fn initialize(
         &mut self,
         _context: &OperationContext,
-        amount: Amount,
+        argument: InitializationArgument,
     ) -> Result<ExecutionResult<Self::Message>, Self::Error> {
         if let Some(owner) = _context.authenticated_signer {
-            self.initialize_accounts(owner, amount).await
+            self.initialize_accounts(owner, argument.amount).await;
+            self.set_admin(owner);
+            self.set_minter(owner);
+            self.set_total_supply(argument.amount);
+            self.set_metadata_blob(argument.metadata_blob);
         }
         Ok(ExecutionResult::default())
     }
@@ -47,13 +54,94 @@ impl Contract for FungibleToken {
                 owner,
                 amount,
                 target_account,
+                memo,
             } => {
+                if self.is_paused() {
+                    return Err(Error::ContractPaused);
+                }
+                Self::check_memo_length(&memo)?;
                 Self::check_account_authentication(context.authenticated_signer, owner)?;
                 self.debit(owner, amount).await?;
+                self.append_history(
+                    owner,
+                    target_account.owner,
+                    target_account.chain_id,
+                    amount,
+                    memo.clone(),
+                    false,
+                )
+                .await;
                 Ok(self
-                    .finish_transfer_to_account(amount, target_account)
+                    .finish_transfer_to_account(owner, amount, target_account, memo)
                     .await)
             }
+            Operation::Pause => {
+                self.check_admin_authentication(context.authenticated_signer)?;
+                self.set_paused(true);
+                Ok(ExecutionResult::default())
+            }
+            Operation::Resume => {
+                self.check_admin_authentication(context.authenticated_signer)?;
+                self.set_paused(false);
+                Ok(ExecutionResult::default())
+            }
+            Operation::Approve {
+                owner,
+                spender,
+                amount,
+            } => {
+                Self::check_account_authentication(context.authenticated_signer, owner)?;
+                self.approve(owner, spender, amount).await;
+                Ok(ExecutionResult::default())
+            }
+            Operation::TransferFrom {
+                spender,
+                source,
+                amount,
+                target_account,
+            } => {
+                if self.is_paused() {
+                    return Err(Error::ContractPaused);
+                }
+                Self::check_account_authentication(context.authenticated_signer, spender)?;
+                // Check both the allowance and the balance before mutating either, so a
+                // `TransferFrom` that fails for insufficient balance can't still burn
+                // through the spender's allowance (and vice versa).
+                if self.allowance(source, spender).await < amount {
+                    return Err(Error::InsufficientAllowance(InsufficientAllowanceError));
+                }
+                if self.balance(&source).await < amount {
+                    return Err(Error::InsufficientBalance(InsufficientBalanceError));
+                }
+                self.spend_allowance(source, spender, amount)
+                    .await
+                    .expect("allowance was just checked to be sufficient");
+                self.debit(source, amount)
+                    .await
+                    .expect("balance was just checked to be sufficient");
+                self.append_history(
+                    source,
+                    target_account.owner,
+                    target_account.chain_id,
+                    amount,
+                    None,
+                    false,
+                )
+                .await;
+                Ok(self
+                    .finish_transfer_to_account(source, amount, target_account, None)
+                    .await)
+            }
+            Operation::Mint { owner, amount } => {
+                self.check_minter_authentication(context.authenticated_signer)?;
+                self.mint(owner, amount).await;
+                Ok(ExecutionResult::default())
+            }
+            Operation::Burn { owner, amount } => {
+                Self::check_account_authentication(context.authenticated_signer, owner)?;
+                self.burn(owner, amount).await?;
+                Ok(ExecutionResult::default())
+            }
         }
     }
 
@@ -63,8 +151,16 @@ impl Contract for FungibleToken {
         message: Self::Message,
     ) -> Result<ExecutionResult<Self::Message>, Self::Error> {
         match message {
-            Message::Credit { amount, owner } => {
+            Message::Credit {
+                amount,
+                owner,
+                source,
+                source_chain,
+                memo,
+            } => {
                 self.credit(owner, amount).await;
+                self.append_history(owner, source, source_chain, amount, memo, true)
+                    .await;
                 Ok(ExecutionResult::default())
             }
         }
@@ -104,18 +200,53 @@ impl FungibleToken {
         Err(Error::IncorrectAuthentication)
     }
 
+    fn check_admin_authentication(&self, authenticated_signer: Option<Owner>) -> Result<(), Error> {
+        if authenticated_signer.is_some() && authenticated_signer == self.admin() {
+            return Ok(());
+        }
+        Err(Error::Unauthorized)
+    }
+
+    fn check_minter_authentication(&self, authenticated_signer: Option<Owner>) -> Result<(), Error> {
+        if authenticated_signer.is_some() && authenticated_signer == self.minter() {
+            return Ok(());
+        }
+        Err(Error::Unauthorized)
+    }
+
+    fn check_memo_length(memo: &Option<String>) -> Result<(), Error> {
+        match memo {
+            Some(memo) if memo.len() > MAX_MEMO_LEN => Err(Error::MemoTooLong),
+            _ => Ok(()),
+        }
+    }
+
     async fn finish_transfer_to_account(
         &mut self,
+        source: Owner,
         amount: Amount,
         account: Account,
+        memo: Option<String>,
     ) -> ExecutionResult<Message> {
         if account.chain_id == system_api::current_chain_id() {
             self.credit(account.owner, amount).await;
+            self.append_history(
+                account.owner,
+                source,
+                account.chain_id,
+                amount,
+                memo,
+                true,
+            )
+            .await;
             ExecutionResult::default()
         } else {
             let message = Message::Credit {
                 owner: account.owner,
-                amount: amount,
+                amount,
+                source,
+                source_chain: system_api::current_chain_id(),
+                memo,
             };
             ExecutionResult::default().with_message(account.chain_id, message)
         }
@@ -141,6 +272,18 @@ pub enum Error {
 
     #[error("Sessions not supported")]
     SessionsNotSupported,
+
+    #[error("This operation is restricted to the contract's admin or minter")]
+    Unauthorized,
+
+    #[error("Transfers are currently paused")]
+    ContractPaused,
+
+    #[error("Insufficient allowance")]
+    InsufficientAllowance(#[from] InsufficientAllowanceError),
+
+    #[error("Memo exceeds the maximum allowed length")]
+    MemoTooLong,
 }
 
 #[cfg(test)]
@@ -172,7 +315,13 @@ pub mod tests {
         let mut fungible_token = FungibleToken::load(store).now_or_never().unwrap().unwrap();
 
         let result = fungible_token
-            .initialize(&dummy_context(), amount)
+            .initialize(
+                &dummy_context(),
+                InitializationArgument {
+                    amount,
+                    metadata_blob: None,
+                },
+            )
             .now_or_never()
             .unwrap();
 
@@ -190,9 +339,249 @@ pub mod tests {
         }
     }
 
+    fn context_signed_by(signer: Owner) -> OperationContext {
+        OperationContext {
+            authenticated_signer: Some(signer),
+            ..dummy_context()
+        }
+    }
+
+    fn dummy_message_context() -> MessageContext {
+        MessageContext {
+            chain_id: ChainId([0; 4].into()),
+            authenticated_signer: Some(creator()),
+            height: BlockHeight(0),
+            index: 0,
+        }
+    }
+
+    #[webassembly_test]
+    pub fn admin_may_pause_and_resume() {
+        let initial_amount = Amount::from_str("50_000").unwrap();
+        let mut fungible = create_and_init(initial_amount);
+
+        let result = fungible
+            .execute_operation(&dummy_context(), Operation::Pause)
+            .now_or_never()
+            .unwrap();
+        assert!(result.is_ok());
+        assert!(fungible.is_paused());
+
+        let result = fungible
+            .execute_operation(&dummy_context(), Operation::Resume)
+            .now_or_never()
+            .unwrap();
+        assert!(result.is_ok());
+        assert!(!fungible.is_paused());
+    }
+
+    #[webassembly_test]
+    pub fn non_admin_may_not_pause_or_resume() {
+        let initial_amount = Amount::from_str("50_000").unwrap();
+        let mut fungible = create_and_init(initial_amount);
+
+        let result = fungible
+            .execute_operation(&context_signed_by(spender()), Operation::Pause)
+            .now_or_never()
+            .unwrap();
+        assert!(matches!(result, Err(Error::Unauthorized)));
+        assert!(!fungible.is_paused());
+
+        fungible
+            .execute_operation(&dummy_context(), Operation::Pause)
+            .now_or_never()
+            .unwrap()
+            .unwrap();
+
+        let result = fungible
+            .execute_operation(&context_signed_by(spender()), Operation::Resume)
+            .now_or_never()
+            .unwrap();
+        assert!(matches!(result, Err(Error::Unauthorized)));
+        assert!(fungible.is_paused());
+    }
+
+    #[webassembly_test]
+    pub fn transfer_fails_while_paused() {
+        let initial_amount = Amount::from_str("50_000").unwrap();
+        let mut fungible = create_and_init(initial_amount);
+        let owner = creator();
+
+        fungible
+            .execute_operation(&dummy_context(), Operation::Pause)
+            .now_or_never()
+            .unwrap()
+            .unwrap();
+
+        let result = fungible
+            .execute_operation(
+                &dummy_context(),
+                Operation::Transfer {
+                    owner,
+                    amount: Amount::from_str("1_000").unwrap(),
+                    target_account: Account {
+                        chain_id: ChainId([1; 4].into()),
+                        owner: spender(),
+                    },
+                    memo: None,
+                },
+            )
+            .now_or_never()
+            .unwrap();
+
+        assert!(matches!(result, Err(Error::ContractPaused)));
+        assert_eq!(
+            fungible.balance(&owner).now_or_never().unwrap(),
+            initial_amount
+        );
+    }
+
+    #[webassembly_test]
+    pub fn incoming_credit_message_still_lands_while_paused() {
+        let initial_amount = Amount::from_str("50_000").unwrap();
+        let mut fungible = create_and_init(initial_amount);
+        let owner = spender();
+        let credit_amount = Amount::from_str("1_000").unwrap();
+
+        fungible
+            .execute_operation(&dummy_context(), Operation::Pause)
+            .now_or_never()
+            .unwrap()
+            .unwrap();
+
+        let source_chain = ChainId([2; 4].into());
+        let result = fungible
+            .execute_message(
+                &dummy_message_context(),
+                Message::Credit {
+                    amount: credit_amount,
+                    owner,
+                    source: creator(),
+                    source_chain,
+                    memo: None,
+                },
+            )
+            .now_or_never()
+            .unwrap();
+
+        assert!(result.is_ok());
+        let history = fungible.transfers(owner, 1).now_or_never().unwrap();
+        assert_eq!(history[0].chain_id, source_chain);
+        assert_eq!(
+            fungible.balance(&owner).now_or_never().unwrap(),
+            credit_amount
+        );
+    }
+
+    #[webassembly_test]
+    pub fn insufficient_allowance_rejected() {
+        let initial_amount = Amount::from_str("50_000").unwrap();
+        let mut fungible = create_and_init(initial_amount);
+        let owner = creator();
+        let spender = spender();
+
+        fungible
+            .approve(owner, spender, Amount::from_str("100").unwrap())
+            .now_or_never()
+            .unwrap();
+
+        let result = fungible
+            .spend_allowance(owner, spender, Amount::from_str("200").unwrap())
+            .now_or_never()
+            .unwrap();
+
+        assert!(result.is_err());
+        assert_eq!(
+            fungible.allowance(owner, spender).now_or_never().unwrap(),
+            Amount::from_str("100").unwrap()
+        );
+    }
+
+    #[webassembly_test]
+    pub fn spend_allowance_decrements_remaining_amount() {
+        let initial_amount = Amount::from_str("50_000").unwrap();
+        let mut fungible = create_and_init(initial_amount);
+        let owner = creator();
+        let spender = spender();
+
+        fungible
+            .approve(owner, spender, Amount::from_str("100").unwrap())
+            .now_or_never()
+            .unwrap();
+
+        fungible
+            .spend_allowance(owner, spender, Amount::from_str("40").unwrap())
+            .now_or_never()
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            fungible.allowance(owner, spender).now_or_never().unwrap(),
+            Amount::from_str("60").unwrap()
+        );
+    }
+
+    #[webassembly_test]
+    pub fn mint_increases_total_supply() {
+        let initial_amount = Amount::from_str("50_000").unwrap();
+        let mut fungible = create_and_init(initial_amount);
+        let owner = creator();
+
+        fungible
+            .mint(owner, Amount::from_str("1_000").unwrap())
+            .now_or_never()
+            .unwrap();
+
+        assert_eq!(
+            fungible.balance(&owner).now_or_never().unwrap(),
+            Amount::from_str("51_000").unwrap()
+        );
+        assert_eq!(fungible.total_supply(), Amount::from_str("51_000").unwrap());
+    }
+
+    #[webassembly_test]
+    pub fn burn_decreases_total_supply() {
+        let initial_amount = Amount::from_str("50_000").unwrap();
+        let mut fungible = create_and_init(initial_amount);
+        let owner = creator();
+
+        fungible
+            .burn(owner, Amount::from_str("1_000").unwrap())
+            .now_or_never()
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            fungible.balance(&owner).now_or_never().unwrap(),
+            Amount::from_str("49_000").unwrap()
+        );
+        assert_eq!(fungible.total_supply(), Amount::from_str("49_000").unwrap());
+    }
+
+    #[webassembly_test]
+    pub fn burn_more_than_balance_is_rejected() {
+        let initial_amount = Amount::from_str("50_000").unwrap();
+        let mut fungible = create_and_init(initial_amount);
+        let owner = creator();
+
+        let result = fungible
+            .burn(owner, Amount::from_str("100_000").unwrap())
+            .now_or_never()
+            .unwrap();
+
+        assert!(result.is_err());
+        assert_eq!(fungible.total_supply(), initial_amount);
+    }
+
     fn creator() -> Owner {
         "1c02a28d03e846b113de238d8880df3c9c802143b73aea5d173466701bee1786"
             .parse()
             .unwrap()
     }
+
+    fn spender() -> Owner {
+        "8b971817e6fb39a45dc37894b97017bf62fc0d8c3d024e66a501a39e478b75e9"
+            .parse()
+            .unwrap()
+    }
 }
@@ -1,5 +1,5 @@
 use async_graphql::{InputObject, Request, Response};
-use linera_sdk::base::{Amount, ChainId, ContractAbi, Owner, ServiceAbi};
+use linera_sdk::base::{Amount, ChainId, ContractAbi, CryptoHash, Owner, ServiceAbi};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize, Deserialize)]
@@ -7,7 +7,7 @@ pub struct FungibleAbi;
 
 impl ContractAbi for FungibleAbi {
     type Parameters = ();
-    type InitializationArgument = Amount;
+    type InitializationArgument = InitializationArgument;
     type Operation = Operation;
     type Message = Message;
     type ApplicationCall = ();
@@ -16,6 +16,14 @@ impl ContractAbi for FungibleAbi {
     type Response = ();
 }
 
+/// The argument passed to `initialize`: the genesis balance plus an optional reference to a
+/// published blob (JSON/PNG) holding the token's human-readable metadata.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct InitializationArgument {
+    pub amount: Amount,
+    pub metadata_blob: Option<CryptoHash>,
+}
+
 impl ServiceAbi for FungibleAbi {
     type Parameters = ();
     type Query = Request;
@@ -28,12 +36,40 @@ pub enum Operation {
         owner: Owner,
         amount: Amount,
         target_account: Account,
+        memo: Option<String>,
+    },
+    /// Pauses all transfers. Only the admin may call this.
+    Pause,
+    /// Resumes transfers after a pause. Only the admin may call this.
+    Resume,
+    Approve {
+        owner: Owner,
+        spender: Owner,
+        amount: Amount,
     },
+    TransferFrom {
+        spender: Owner,
+        source: Owner,
+        amount: Amount,
+        target_account: Account,
+    },
+    /// Mints new tokens into `owner`'s account. Only the minter may call this.
+    Mint { owner: Owner, amount: Amount },
+    /// Burns tokens out of `owner`'s account. Only `owner` may call this.
+    Burn { owner: Owner, amount: Amount },
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 pub enum Message {
-    Credit { amount: Amount, owner: Owner },
+    Credit {
+        amount: Amount,
+        owner: Owner,
+        source: Owner,
+        /// The chain the transfer was sent from, so the receiving chain can record where an
+        /// incoming transfer actually came from rather than its own chain id.
+        source_chain: ChainId,
+        memo: Option<String>,
+    },
 }
 
 #[derive(
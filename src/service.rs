@@ -2,8 +2,8 @@
 
 mod state;
 
-use self::state::FungibleToken;
-use async_graphql::{EmptySubscription, Object, Request, Response, Schema};
+use self::state::{AccountBalance, FungibleToken, TransferRecord};
+use async_graphql::{EmptySubscription, MergedObject, Object, Request, Response, Schema};
 use async_trait::async_trait;
 use fungible::{Account, Operation};
 use linera_sdk::base::{Amount, Owner};
@@ -27,18 +27,133 @@ impl Service for FungibleToken {
         _context: &QueryContext,
         request: Request,
     ) -> Result<Response, Self::Error> {
-        let schema = Schema::build(self.clone(), MutationRoot {}, EmptySubscription).finish();
+        let query_root = QueryRoot(
+            self.clone(),
+            ExtraQueries {
+                state: self.clone(),
+            },
+        );
+        let schema = Schema::build(query_root, MutationRoot {}, EmptySubscription).finish();
         let response = schema.execute(request).await;
         Ok(response)
     }
 }
 
+/// Queries merged alongside `FungibleToken`'s derived `GraphQLView` object. Most aren't
+/// simple view-field accessors; `totalSupply` has the same name and value as the derived
+/// field for the `total_supply` register.
+struct ExtraQueries {
+    state: Arc<FungibleToken>,
+}
+
+#[Object]
+impl ExtraQueries {
+    /// The most recent transfers into or out of `owner`, newest first.
+    async fn transfers(&self, owner: Owner, limit: usize) -> Vec<TransferRecord> {
+        self.state.transfers(owner, limit).await
+    }
+
+    /// The published data blob backing this token's metadata (name, ticker, decimals, icon),
+    /// hex-encoded. The blob isn't assumed to be UTF-8 text (an icon, for instance, is
+    /// typically a PNG), so the raw bytes are encoded rather than decoded as a string.
+    /// Returns `None` if no metadata blob was set at initialization.
+    async fn metadata(&self) -> Option<String> {
+        let hash = self.state.metadata_blob()?;
+        let bytes = linera_sdk::service::system_api::find_data_blob(&hash).await;
+        Some(bytes.iter().map(|byte| format!("{byte:02x}")).collect())
+    }
+
+    /// Lists accounts in a stable order, `limit` at a time, resuming after `start_after`.
+    ///
+    /// Named `accountsPage` rather than `accounts` because `FungibleToken`'s derived
+    /// `GraphQLView` already exposes a field of that name for single-owner balance lookups.
+    /// Returns `None` if `start_after` is set but doesn't name a known account (a stale cursor).
+    async fn accounts_page(
+        &self,
+        limit: usize,
+        start_after: Option<Owner>,
+    ) -> Option<Vec<AccountBalance>> {
+        self.state.list_accounts(limit, start_after).await
+    }
+
+    /// The number of distinct accounts holding a non-zero balance.
+    async fn holder_count(&self) -> u64 {
+        self.state.holder_count().await
+    }
+
+    /// The `n` accounts with the largest balances, largest first.
+    async fn richest_accounts(&self, n: usize) -> Vec<AccountBalance> {
+        self.state.richest_accounts(n).await
+    }
+
+    /// The amount `spender` is currently allowed to transfer out of `owner`'s account.
+    async fn allowance(&self, owner: Owner, spender: Owner) -> Amount {
+        self.state.allowance(owner, spender).await
+    }
+
+    /// The total amount of tokens in circulation across all accounts.
+    async fn total_supply(&self) -> Amount {
+        self.state.total_supply()
+    }
+}
+
+#[derive(MergedObject)]
+struct QueryRoot(Arc<FungibleToken>, ExtraQueries);
+
 struct MutationRoot;
 
 #[Object]
 impl MutationRoot {
-    async fn transfer(&self, owner: Owner, amount: Amount, target_account: Account) -> Vec<u8> {
-        bcs::to_bytes(&Operation::Transfer { owner, amount, target_account }).unwrap()
+    async fn transfer(
+        &self,
+        owner: Owner,
+        amount: Amount,
+        target_account: Account,
+        memo: Option<String>,
+    ) -> Vec<u8> {
+        bcs::to_bytes(&Operation::Transfer {
+            owner,
+            amount,
+            target_account,
+            memo,
+        })
+        .unwrap()
+    }
+
+    async fn pause(&self) -> Vec<u8> {
+        bcs::to_bytes(&Operation::Pause).unwrap()
+    }
+
+    async fn resume(&self) -> Vec<u8> {
+        bcs::to_bytes(&Operation::Resume).unwrap()
+    }
+
+    async fn approve(&self, owner: Owner, spender: Owner, amount: Amount) -> Vec<u8> {
+        bcs::to_bytes(&Operation::Approve { owner, spender, amount }).unwrap()
+    }
+
+    async fn transfer_from(
+        &self,
+        spender: Owner,
+        source: Owner,
+        amount: Amount,
+        target_account: Account,
+    ) -> Vec<u8> {
+        bcs::to_bytes(&Operation::TransferFrom {
+            spender,
+            source,
+            amount,
+            target_account,
+        })
+        .unwrap()
+    }
+
+    async fn mint(&self, owner: Owner, amount: Amount) -> Vec<u8> {
+        bcs::to_bytes(&Operation::Mint { owner, amount }).unwrap()
+    }
+
+    async fn burn(&self, owner: Owner, amount: Amount) -> Vec<u8> {
+        bcs::to_bytes(&Operation::Burn { owner, amount }).unwrap()
     }
 }
 
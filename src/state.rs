@@ -1,12 +1,41 @@
-use linera_sdk::base::{Amount, Owner};
-use linera_sdk::views::{MapView, ViewStorageContext};
+use async_graphql::SimpleObject;
+use linera_sdk::base::{Amount, ChainId, CryptoHash, Owner};
+use linera_sdk::views::{MapView, RegisterView, ViewStorageContext};
 use linera_views::views::{GraphQLView, RootView};
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+/// The number of past transfers kept per owner before the oldest entries are evicted.
+const MAX_HISTORY_LEN: usize = 256;
+
 #[derive(RootView, GraphQLView)]
 #[view(context = "ViewStorageContext")]
 pub struct FungibleToken {
     accounts: MapView<Owner, Amount>,
+    admin: RegisterView<Option<Owner>>,
+    is_paused: RegisterView<bool>,
+    allowances: MapView<(Owner, Owner), Amount>,
+    minter: RegisterView<Option<Owner>>,
+    total_supply: RegisterView<Amount>,
+    history: MapView<Owner, Vec<TransferRecord>>,
+    metadata_blob: RegisterView<Option<CryptoHash>>,
+}
+
+/// A single entry in an owner's transfer history.
+#[derive(Clone, Debug, Deserialize, Serialize, SimpleObject)]
+pub struct TransferRecord {
+    pub counterparty: Owner,
+    pub chain_id: ChainId,
+    pub amount: Amount,
+    pub memo: Option<String>,
+    pub incoming: bool,
+}
+
+/// An owner's balance, as returned by account-listing queries.
+#[derive(Clone, Copy, Debug, SimpleObject)]
+pub struct AccountBalance {
+    pub owner: Owner,
+    pub amount: Amount,
 }
 
 #[allow(dead_code)]
@@ -17,6 +46,46 @@ impl FungibleToken {
             .expect("Error in insert statemet")
     }
 
+    pub fn set_admin(&mut self, admin: Owner) {
+        self.admin.set(Some(admin));
+    }
+
+    pub fn admin(&self) -> Option<Owner> {
+        *self.admin.get()
+    }
+
+    pub fn is_paused(&self) -> bool {
+        *self.is_paused.get()
+    }
+
+    pub fn set_paused(&mut self, paused: bool) {
+        self.is_paused.set(paused);
+    }
+
+    pub fn set_minter(&mut self, minter: Owner) {
+        self.minter.set(Some(minter));
+    }
+
+    pub fn minter(&self) -> Option<Owner> {
+        *self.minter.get()
+    }
+
+    pub fn total_supply(&self) -> Amount {
+        *self.total_supply.get()
+    }
+
+    pub fn set_total_supply(&mut self, amount: Amount) {
+        self.total_supply.set(amount);
+    }
+
+    pub fn set_metadata_blob(&mut self, metadata_blob: Option<CryptoHash>) {
+        self.metadata_blob.set(metadata_blob);
+    }
+
+    pub fn metadata_blob(&self) -> Option<CryptoHash> {
+        *self.metadata_blob.get()
+    }
+
     pub async fn balance(&self, account: &Owner) -> Amount {
         self.accounts
             .get(account)
@@ -47,8 +116,179 @@ impl FungibleToken {
             .expect("Failed to insert");
         Ok(())
     }
+
+    pub async fn mint(&mut self, owner: Owner, amount: Amount) {
+        self.credit(owner, amount).await;
+        let mut total_supply = self.total_supply();
+        total_supply.saturating_add_assign(amount);
+        self.set_total_supply(total_supply);
+    }
+
+    pub async fn burn(
+        &mut self,
+        owner: Owner,
+        amount: Amount,
+    ) -> Result<(), InsufficientBalanceError> {
+        self.debit(owner, amount).await?;
+        let mut total_supply = self.total_supply();
+        total_supply.try_sub_assign(amount).expect(
+            "total supply should never be lower than an individual account's balance",
+        );
+        self.set_total_supply(total_supply);
+        Ok(())
+    }
+
+    pub async fn approve(&mut self, owner: Owner, spender: Owner, amount: Amount) {
+        self.allowances
+            .insert(&(owner, spender), amount)
+            .expect("Failed to insert")
+    }
+
+    pub async fn allowance(&self, owner: Owner, spender: Owner) -> Amount {
+        self.allowances
+            .get(&(owner, spender))
+            .await
+            .expect("Failure in retrieval")
+            .unwrap_or_default()
+    }
+
+    pub async fn spend_allowance(
+        &mut self,
+        owner: Owner,
+        spender: Owner,
+        amount: Amount,
+    ) -> Result<(), InsufficientAllowanceError> {
+        let mut remaining = self.allowance(owner, spender).await;
+        remaining
+            .try_sub_assign(amount)
+            .map_err(|_| InsufficientAllowanceError)?;
+        self.allowances
+            .insert(&(owner, spender), remaining)
+            .expect("Failed to insert");
+        Ok(())
+    }
+
+    pub async fn append_history(
+        &mut self,
+        owner: Owner,
+        counterparty: Owner,
+        chain_id: ChainId,
+        amount: Amount,
+        memo: Option<String>,
+        incoming: bool,
+    ) {
+        let mut records = self
+            .history
+            .get(&owner)
+            .await
+            .expect("Failure in retrieval")
+            .unwrap_or_default();
+        records.push(TransferRecord {
+            counterparty,
+            chain_id,
+            amount,
+            memo,
+            incoming,
+        });
+        if records.len() > MAX_HISTORY_LEN {
+            let overflow = records.len() - MAX_HISTORY_LEN;
+            records.drain(0..overflow);
+        }
+        self.history
+            .insert(&owner, records)
+            .expect("Failed to insert");
+    }
+
+    pub async fn transfers(&self, owner: Owner, limit: usize) -> Vec<TransferRecord> {
+        let mut records = self
+            .history
+            .get(&owner)
+            .await
+            .expect("Failure in retrieval")
+            .unwrap_or_default();
+        let start = records.len().saturating_sub(limit);
+        let mut recent = records.split_off(start);
+        recent.reverse();
+        recent
+    }
+
+    /// Lists accounts in a stable order, `limit` at a time, resuming after `start_after`.
+    ///
+    /// Returns `None` if `start_after` doesn't name an account that was actually seen during
+    /// the scan (a stale or otherwise unknown cursor), so callers can tell that apart from a
+    /// valid cursor that's simply past the last holder.
+    ///
+    /// `MapView::for_each_index_value` has no early-termination primitive, so every key is
+    /// still visited even after `limit` items have been collected; that's accepted here given
+    /// the account counts this demo app deals with.
+    pub async fn list_accounts(
+        &self,
+        limit: usize,
+        start_after: Option<Owner>,
+    ) -> Option<Vec<AccountBalance>> {
+        let mut accounts = Vec::new();
+        let mut skipping = start_after.is_some();
+        self.accounts
+            .for_each_index_value(|owner, amount| {
+                if skipping {
+                    if Some(owner) == start_after {
+                        skipping = false;
+                    }
+                    return Ok(());
+                }
+                if amount != Amount::ZERO && accounts.len() < limit {
+                    accounts.push(AccountBalance { owner, amount });
+                }
+                Ok(())
+            })
+            .await
+            .expect("Failed to iterate accounts");
+        if skipping {
+            return None;
+        }
+        Some(accounts)
+    }
+
+    /// The number of distinct accounts holding a non-zero balance.
+    ///
+    /// `debit`/`burn` leave a zero-balance entry in `accounts` rather than removing it, so
+    /// those drained accounts are filtered out here to match what callers expect "holder"
+    /// to mean.
+    pub async fn holder_count(&self) -> u64 {
+        let mut count = 0u64;
+        self.accounts
+            .for_each_index_value(|_owner, amount| {
+                if amount != Amount::ZERO {
+                    count += 1;
+                }
+                Ok(())
+            })
+            .await
+            .expect("Failed to iterate accounts");
+        count
+    }
+
+    pub async fn richest_accounts(&self, n: usize) -> Vec<AccountBalance> {
+        let mut accounts = Vec::new();
+        self.accounts
+            .for_each_index_value(|owner, amount| {
+                if amount != Amount::ZERO {
+                    accounts.push(AccountBalance { owner, amount });
+                }
+                Ok(())
+            })
+            .await
+            .expect("Failed to iterate accounts");
+        accounts.sort_by(|a, b| b.amount.cmp(&a.amount));
+        accounts.truncate(n);
+        accounts
+    }
 }
 
 #[derive(Clone, Copy, Debug, Error)]
 #[error("Insufficient balance error")]
 pub struct InsufficientBalanceError;
+
+#[derive(Clone, Copy, Debug, Error)]
+#[error("Insufficient allowance error")]
+pub struct InsufficientAllowanceError;
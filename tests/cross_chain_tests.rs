@@ -3,7 +3,7 @@
 use async_graphql::InputType;
 use linera_sdk::base::{Amount, ApplicationId, Owner};
 use linera_sdk::test::{ActiveChain, TestValidator};
-use fungible::{Account, FungibleAbi, Operation};
+use fungible::{Account, FungibleAbi, InitializationArgument, Operation};
 
 #[tokio::test]
 async fn test_cross_chain_transfer() {
@@ -18,7 +18,10 @@ async fn test_cross_chain_transfer() {
         .create_application::<fungible::FungibleAbi>(
             bytecode_id,
             (),
-            initial_amount,
+            InitializationArgument {
+                amount: initial_amount,
+                metadata_blob: None,
+            },
             vec![]
         ).await;
 
@@ -34,7 +37,8 @@ async fn test_cross_chain_transfer() {
                 target_account: Account {
                     chain_id: receiver_chain.id(),
                     owner: receiver_account
-                }
+                },
+                memo: None,
             },
         );
     }).await;